@@ -1,8 +1,21 @@
 use std::io::{Result, Write};
 use std::path::{Path, PathBuf};
-use toml_edit::{Decor, Document, Item, Table, Value};
+use toml_edit::{Decor, ImDocument, Item, Table, Value};
 
-fn resolve_config_path(platform: Option<&str>) -> Result<PathBuf> {
+/// A single config source, in the order it's applied. Keys from a later
+/// layer override keys from an earlier one. `content` is kept alongside
+/// `table` so validation errors can be reported with an accurate line number.
+struct Layer {
+    path: PathBuf,
+    table: Table,
+    content: String,
+    origin: &'static str,
+}
+
+/// Resolves the ordered list of config layers for the requested platform:
+/// the built-in `defconfig.toml`, then the selected platform file (if any),
+/// then an optional project-local `axconfig.local.toml` overlay.
+fn resolve_layers(platform: Option<&str>) -> Result<Vec<Layer>> {
     let root_dir = PathBuf::from(std::env!("CARGO_MANIFEST_DIR"));
     let config_dir = root_dir.join("platforms");
 
@@ -18,22 +31,71 @@ fn resolve_config_path(platform: Option<&str>) -> Result<PathBuf> {
         })
         .collect::<Vec<_>>();
 
-    let path = match platform {
-        None | Some("") => "defconfig.toml".into(),
-        Some(plat) if builtin_platforms.contains(&plat.to_string()) => {
+    let mut layers = Vec::new();
+
+    let defconfig_path = PathBuf::from("defconfig.toml");
+    let (content, table) = load_config_toml(&defconfig_path)?;
+    layers.push(Layer {
+        path: defconfig_path,
+        table,
+        content,
+        origin: "built-in defconfig",
+    });
+
+    if let Some(plat) = platform.filter(|plat| !plat.is_empty()) {
+        let platform_path = if builtin_platforms.contains(&plat.to_string()) {
             config_dir.join(format!("{plat}.toml"))
-        }
-        Some(plat) => {
-            let path = PathBuf::from(&plat);
+        } else {
+            let path = PathBuf::from(plat);
             if path.is_absolute() {
                 path
             } else {
                 root_dir.join(plat)
             }
-        }
-    };
+        };
+        let (content, table) = load_config_toml(&platform_path)?;
+        layers.push(Layer {
+            path: platform_path,
+            table,
+            content,
+            origin: "platform",
+        });
+    }
+
+    let local_path = root_dir.join("axconfig.local.toml");
+    if local_path.exists() {
+        let (content, table) = load_config_toml(&local_path)?;
+        layers.push(Layer {
+            path: local_path,
+            table,
+            content,
+            origin: "project-local overlay",
+        });
+    }
 
-    Ok(path)
+    Ok(layers)
+}
+
+/// Merges an ordered list of layers into a single `Table`, with later layers
+/// overriding earlier ones. A key that's overridden without its own comment
+/// keeps the comment contributed by the earlier layer it replaced.
+fn merge_layers(layers: &[Layer]) -> Table {
+    let mut merged = Table::new();
+    for layer in layers {
+        for (key, item) in layer.table.iter() {
+            let comments = get_comments(&layer.table, key)
+                .or_else(|| {
+                    // An `ImDocument`-parsed table holds its decor as spans only,
+                    // so read the comment back out of the layer's source text.
+                    let span = layer.table.key_decor(key)?.prefix()?.span()?;
+                    Some(layer.content[span].trim()).filter(|s| !s.is_empty())
+                })
+                .or_else(|| get_comments(&merged, key))
+                .map(String::from);
+            add_config(&mut merged, key, item.clone(), comments.as_deref());
+        }
+    }
+    merged
 }
 
 fn get_comments<'a>(config: &'a Table, key: &str) -> Option<&'a str> {
@@ -42,6 +104,7 @@ fn get_comments<'a>(config: &'a Table, key: &str) -> Option<&'a str> {
         .and_then(|d| d.prefix())
         .and_then(|s| s.as_str())
         .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
 }
 
 fn add_config(config: &mut Table, key: &str, item: Item, comments: Option<&str>) {
@@ -53,48 +116,215 @@ fn add_config(config: &mut Table, key: &str, item: Item, comments: Option<&str>)
     }
 }
 
-fn load_config_toml(config_path: &Path) -> Result<Table> {
+fn load_config_toml(config_path: &Path) -> Result<(String, Table)> {
     let config_content = std::fs::read_to_string(config_path)?;
+    // Parse as an `ImDocument` rather than a `Document`/`DocumentMut`: the
+    // latter despans itself on construction, so every `Item::span()` would
+    // read back `None` and `fail_validation`'s line numbers would never work.
     let toml = config_content
-        .parse::<Document>()
+        .parse::<ImDocument<String>>()
         .expect("failed to parse config file")
         .as_table()
         .clone();
-    Ok(toml)
+    Ok((config_content, toml))
 }
 
-fn gen_config_rs(config_path: &Path) -> Result<Vec<u8>> {
-    fn is_num(s: &str) -> bool {
-        let s = s.replace('_', "");
-        if s.parse::<usize>().is_ok() {
-            true
-        } else if let Some(s) = s.strip_prefix("0x") {
-            usize::from_str_radix(s, 16).is_ok()
-        } else {
-            false
+/// Overrides any config key with an environment variable named
+/// `AX_CONFIG_<KEY>` (key uppercased, `-` replaced by `_`), so that CI and
+/// downstream crates can tweak memory sizes, addresses, etc. without editing
+/// the platform TOML files. Panics if an `AX_CONFIG_*` variable doesn't
+/// correspond to a known key. Keys nested under a sub-table (e.g.
+/// `[devices.uart]`) aren't visited here, so they can't be overridden this
+/// way yet.
+///
+/// `cargo:rerun-if-env-changed` is emitted for every known key unconditionally,
+/// not just the ones currently set, so a build stays correct when a var is
+/// set, unset, or changed between invocations.
+fn apply_env_overrides(config: &mut Table) {
+    let keys = config
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .collect::<Vec<_>>();
+
+    for key in &keys {
+        let var = format!("AX_CONFIG_{}", key.to_uppercase().replace('-', "_"));
+        println!("cargo:rerun-if-env-changed={var}");
+    }
+
+    for (var, value) in std::env::vars() {
+        let Some(suffix) = var.strip_prefix("AX_CONFIG_") else {
+            continue;
+        };
+        let key = keys
+            .iter()
+            .find(|key| key.to_uppercase().replace('-', "_") == suffix)
+            .unwrap_or_else(|| panic!("unknown config key overridden by env var {var}: {suffix}"));
+
+        let comments = get_comments(config, key).map(String::from);
+        let new_item = match config[key.as_str()].as_value() {
+            Some(Value::Array(_)) => panic!("cannot override array config key {key} via {var}"),
+            Some(v) if is_num_value(v) => toml_edit::value(
+                parse_override_int(&value)
+                    .unwrap_or_else(|| panic!("{var} must be a number, got {value:?}")),
+            ),
+            _ => toml_edit::value(value),
+        };
+        add_config(config, key, new_item, comments.as_deref());
+    }
+}
+
+/// Parses an env-override value the same way `is_num_value` recognizes a
+/// numeric config value, so a hex-valued key (e.g. `uart-base = "0x9000000"`)
+/// can still be overridden with another hex literal via `AX_CONFIG_*`.
+fn parse_override_int(s: &str) -> Option<i64> {
+    let s = s.replace('_', "");
+    match s.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        None => s.parse::<i64>().ok(),
+    }
+}
+
+fn is_num_value(value: &Value) -> bool {
+    match value {
+        Value::Integer(_) => true,
+        Value::String(s) => {
+            let s = s.value().replace('_', "");
+            s.parse::<usize>().is_ok()
+                || s.strip_prefix("0x")
+                    .is_some_and(|s| usize::from_str_radix(s, 16).is_ok())
         }
+        _ => false,
     }
+}
 
-    // Load TOML config file
-    let mut config = if config_path == Path::new("defconfig.toml") {
-        load_config_toml(config_path)?
-    } else {
-        // Set default values for missing items
-        let defconfig = load_config_toml(Path::new("defconfig.toml"))?;
-        let mut config = load_config_toml(config_path)?;
-
-        for (key, item) in defconfig.iter() {
-            if !config.contains_key(key) {
-                add_config(
-                    &mut config,
-                    key,
-                    item.clone(),
-                    get_comments(&defconfig, key),
+/// Keys synthesized or relied on by `gen_config_rs` itself, not sourced from
+/// any single layer's own TOML content. Schema validation exempts them from
+/// the "unknown config key" check; a defconfig schema may still opt into
+/// validating them by declaring e.g. `[schema.platform]` explicitly.
+const BUILTIN_KEYS: &[&str] = &["platform", "smp"];
+
+/// Validates every layer's own keys against the schema declared in the
+/// `[schema]` table of `defconfig.toml` (the first layer). Returns that
+/// schema table so the caller can separately check `required` keys once the
+/// fully-merged config (including synthesized keys like `smp`) exists.
+/// A schema entry looks like:
+/// ```toml
+/// [schema.smp]
+/// type = "usize"
+/// required = true
+///
+/// [schema.arch]
+/// type = "str"
+/// allowed = ["riscv64", "aarch64", "x86_64"]
+/// ```
+/// When `defconfig.toml` declares no `[schema]` table, no layer is validated
+/// at all; a `[schema]` table in any other layer is ignored. Keys
+/// nested under a sub-table (e.g. `[devices.uart]`) are not visited by this
+/// pass; only top-level keys are checked.
+fn validate_config(layers: &[Layer]) -> Option<&Table> {
+    let schema = layers[0].table.get("schema").and_then(Item::as_table)?;
+
+    for layer in layers {
+        for (key, item) in layer.table.iter() {
+            if key == "schema" || (BUILTIN_KEYS.contains(&key) && !schema.contains_key(key)) {
+                continue;
+            }
+            let Item::Value(value) = item else {
+                continue;
+            };
+            let Some(rule) = schema.get(key).and_then(Item::as_table) else {
+                fail_validation(
+                    layer,
+                    item,
+                    &format!("unknown config key `{key}` (not declared in the defconfig schema)"),
                 );
+            };
+            if let Some(ty) = rule.get("type").and_then(Item::as_str) {
+                if !matches_type(value, ty) {
+                    fail_validation(
+                        layer,
+                        item,
+                        &format!("config key `{key}` should have type `{ty}`"),
+                    );
+                }
+            }
+            if let (Value::String(s), Some(allowed)) =
+                (value, rule.get("allowed").and_then(Item::as_array))
+            {
+                if !allowed.iter().any(|v| v.as_str() == Some(s.value())) {
+                    fail_validation(
+                        layer,
+                        item,
+                        &format!(
+                            "config key `{key}` has value `{}` outside its allowed set",
+                            s.value()
+                        ),
+                    );
+                }
             }
         }
-        config
-    };
+    }
+
+    Some(schema)
+}
+
+/// Checks that every key the schema marks `required` is present in the
+/// fully-merged config. Must run after synthesized keys like `smp` are
+/// added, otherwise a schema requiring `smp` would reject every build.
+fn check_required_keys(schema: &Table, merged: &Table) {
+    for (key, rule) in schema.iter() {
+        let Some(rule) = rule.as_table() else {
+            continue;
+        };
+        if rule.get("required").and_then(Item::as_bool) == Some(true) && !merged.contains_key(key) {
+            println!("cargo:warning=defconfig.toml: missing required config key `{key}`");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn matches_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "bool" => matches!(value, Value::Boolean(_)),
+        "usize" | "isize" | "u64" => is_num_value(value) || matches!(value, Value::InlineTable(_)),
+        "str" => matches!(value, Value::String(_)) && !is_num_value(value),
+        "array" => matches!(value, Value::Array(_)),
+        _ => true,
+    }
+}
+
+/// Reports `path:line: message` via `cargo:warning` using the item's span
+/// (if the parser recorded one) to locate the offending key, then aborts the
+/// build so a broken `config.rs` is never generated.
+fn fail_validation(layer: &Layer, item: &Item, message: &str) -> ! {
+    let line = item
+        .span()
+        .map(|span| line_of(&layer.content, span.start))
+        .unwrap_or(0);
+    println!("cargo:warning={}:{line}: {message}", layer.path.display());
+    std::process::exit(1);
+}
+
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+fn is_num(s: &str) -> bool {
+    let s = s.replace('_', "");
+    if s.parse::<isize>().is_ok() {
+        true
+    } else if let Some(s) = s.strip_prefix("0x") {
+        usize::from_str_radix(s, 16).is_ok()
+    } else {
+        false
+    }
+}
+
+fn gen_config_rs(layers: &[Layer]) -> Result<Vec<u8>> {
+    let mut config = merge_layers(layers);
+    config.remove("schema");
+
+    let schema = validate_config(layers);
 
     add_config(
         &mut config,
@@ -103,6 +333,14 @@ fn gen_config_rs(config_path: &Path) -> Result<Vec<u8>> {
         Some("# Number of CPUs"),
     );
 
+    apply_env_overrides(&mut config);
+
+    if let Some(schema) = schema {
+        check_required_keys(schema, &config);
+    }
+
+    let cfgs = emit_rustc_cfgs(&config, schema);
+
     // Generate config.rs
     let mut output = Vec::new();
     writeln!(
@@ -112,62 +350,262 @@ fn gen_config_rs(config_path: &Path) -> Result<Vec<u8>> {
     )?;
     writeln!(output, "// Generated by build.rs, DO NOT edit!\n")?;
 
+    writeln!(output, "// Config layers, in override order:")?;
+    for layer in layers {
+        let keys = layer
+            .table
+            .iter()
+            .map(|(key, _)| key.to_uppercase().replace('-', "_"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            output,
+            "//   - {} ({}): {}",
+            layer.path.display(),
+            layer.origin,
+            keys
+        )?;
+    }
+    writeln!(output)?;
+
+    writeln!(output, "// available cfgs:")?;
+    for cfg in &cfgs {
+        writeln!(output, "//   {cfg}")?;
+    }
+    writeln!(output)?;
+
+    emit_config_items(&mut output, &config, 0, is_num)?;
+
+    Ok(output)
+}
+
+/// Turns boolean (or `{ cfg = true }`-annotated) and enumerated string config
+/// keys into `cargo:rustc-cfg` lines, so downstream crates can write
+/// `#[cfg(axconfig_<key>)]` or `#[cfg(axconfig_arch = "riscv64")]`. Returns the
+/// emitted cfg names for the generated file's discovery comment.
+///
+/// A string key only counts as "enumerated" when the defconfig schema
+/// declares an `allowed` set for it; free-form strings (platform names,
+/// paths, ...) are left alone so they don't cfg-pollute every build.
+fn emit_rustc_cfgs(config: &Table, schema: Option<&Table>) -> Vec<String> {
+    let mut cfgs = Vec::new();
     for (key, item) in config.iter() {
+        let cfg_key = key.replace('-', "_");
+        let is_enumerated = schema
+            .and_then(|schema| schema.get(key))
+            .and_then(Item::as_table)
+            .is_some_and(|rule| rule.contains_key("allowed"));
+        let cfg = match item {
+            Item::Value(Value::Boolean(b)) if *b.value() => Some(format!("axconfig_{cfg_key}")),
+            Item::Value(Value::InlineTable(it))
+                if it.get("cfg").and_then(Value::as_bool) == Some(true) =>
+            {
+                Some(format!("axconfig_{cfg_key}"))
+            }
+            Item::Value(Value::String(s)) if is_enumerated => {
+                Some(format!("axconfig_{cfg_key}=\"{}\"", s.value()))
+            }
+            _ => None,
+        };
+        if let Some(cfg) = cfg {
+            println!("cargo:rustc-cfg={cfg}");
+            cfgs.push(cfg);
+        }
+    }
+    cfgs
+}
+
+/// Emits `pub const`s for the leaf values in `table` (booleans, integers,
+/// strings, typed inline tables, integer arrays and the region-pair arrays),
+/// recursing into sub-tables as nested `pub mod` blocks so `[devices.uart]`
+/// becomes `pub mod devices { pub mod uart { ... } }`. Floats, datetimes and
+/// other arrays are skipped.
+fn emit_config_items(
+    output: &mut Vec<u8>,
+    table: &Table,
+    depth: usize,
+    is_num: fn(&str) -> bool,
+) -> Result<()> {
+    let pad = "    ".repeat(depth);
+    for (key, item) in table.iter() {
         let var_name = key.to_uppercase().replace('-', "_");
-        if let Item::Value(value) = item {
-            let comments = get_comments(&config, key)
-                .unwrap_or_default()
-                .replace('#', "///");
-            match value {
-                Value::String(s) => {
-                    writeln!(output, "{comments}")?;
-                    let s = s.value();
-                    if is_num(s) {
-                        writeln!(output, "pub const {var_name}: usize = {s};")?;
-                    } else {
-                        writeln!(output, "pub const {var_name}: &str = \"{s}\";")?;
+        match item {
+            Item::Value(value) => {
+                let comments = get_comments(table, key)
+                    .unwrap_or_default()
+                    .replace('#', "///");
+                match value {
+                    Value::Boolean(b) => {
+                        writeln!(output, "{pad}{comments}")?;
+                        writeln!(output, "{pad}pub const {var_name}: bool = {};", b.value())?;
                     }
-                }
-                Value::Array(regions) => {
-                    if key != "mmio-regions" && key != "virtio-mmio-regions" && key != "pci-ranges"
-                    {
-                        continue;
+                    Value::Integer(i) => {
+                        writeln!(output, "{pad}{comments}")?;
+                        let ty = if *i.value() < 0 { "isize" } else { "usize" };
+                        // `{i}` (not `{}, i.value()`) keeps the literal's original
+                        // spelling, e.g. a hex address written as `0x1000`.
+                        writeln!(output, "{pad}pub const {var_name}: {ty} = {i};")?;
+                    }
+                    Value::String(s) => {
+                        writeln!(output, "{pad}{comments}")?;
+                        let s = s.value();
+                        if is_num(s) {
+                            let ty = if s.starts_with('-') { "isize" } else { "usize" };
+                            writeln!(output, "{pad}pub const {var_name}: {ty} = {s};")?;
+                        } else {
+                            writeln!(output, "{pad}pub const {var_name}: &str = \"{s}\";")?;
+                        }
+                    }
+                    Value::InlineTable(it) => {
+                        // `{ type = "u64", value = "0x..." }` forces the emitted Rust
+                        // type, e.g. for 64-bit MMIO bases on 32-bit hosts.
+                        let ty = it.get("type").and_then(Value::as_str).unwrap_or("usize");
+                        let val = it.get("value").and_then(Value::as_str).unwrap_or_default();
+                        writeln!(output, "{pad}{comments}")?;
+                        match ty {
+                            "str" => {
+                                writeln!(output, "{pad}pub const {var_name}: &str = \"{val}\";")?
+                            }
+                            _ => writeln!(output, "{pad}pub const {var_name}: {ty} = {val};")?,
+                        }
                     }
-                    writeln!(output, "{comments}")?;
-                    writeln!(output, "pub const {var_name}: &[(usize, usize)] = &[")?;
-                    for r in regions.iter() {
-                        let r = r.as_array().unwrap();
-                        writeln!(
-                            output,
-                            "    ({}, {}),",
-                            r.get(0).unwrap().as_str().unwrap(),
-                            r.get(1).unwrap().as_str().unwrap()
-                        )?;
+                    Value::Array(arr) => {
+                        if key == "mmio-regions"
+                            || key == "virtio-mmio-regions"
+                            || key == "pci-ranges"
+                        {
+                            writeln!(output, "{pad}{comments}")?;
+                            writeln!(output, "{pad}pub const {var_name}: &[(usize, usize)] = &[")?;
+                            for r in arr.iter() {
+                                let r = r.as_array().unwrap();
+                                writeln!(
+                                    output,
+                                    "{pad}    ({}, {}),",
+                                    r.get(0).unwrap().as_str().unwrap(),
+                                    r.get(1).unwrap().as_str().unwrap()
+                                )?;
+                            }
+                            writeln!(output, "{pad}];")?;
+                        } else if !arr.is_empty()
+                            && arr.iter().all(|v| matches!(v, Value::Integer(_)))
+                        {
+                            let ty = if arr.iter().any(|v| v.as_integer().unwrap() < 0) {
+                                "isize"
+                            } else {
+                                "usize"
+                            };
+                            writeln!(output, "{pad}{comments}")?;
+                            writeln!(output, "{pad}pub const {var_name}: &[{ty}] = &[")?;
+                            for v in arr.iter() {
+                                writeln!(output, "{pad}    {v},")?;
+                            }
+                            writeln!(output, "{pad}];")?;
+                        }
                     }
-                    writeln!(output, "];")?;
+                    _ => {}
                 }
-                _ => {}
             }
+            Item::Table(sub) => {
+                let mod_name = key.replace('-', "_");
+                writeln!(output, "{pad}pub mod {mod_name} {{")?;
+                emit_config_items(output, sub, depth + 1, is_num)?;
+                writeln!(output, "{pad}}}")?;
+            }
+            _ => {}
         }
     }
-
-    Ok(output)
+    Ok(())
 }
 
 fn main() -> Result<()> {
     let platform = option_env!("AX_PLATFORM");
-    let config_path = resolve_config_path(platform)?;
+    let layers = resolve_layers(platform)?;
 
-    println!("Reading config file: {:?}", config_path);
-    let config_rs = gen_config_rs(&config_path)?;
+    for layer in &layers {
+        println!("Reading config layer: {:?}", layer.path);
+    }
+    let config_rs = gen_config_rs(&layers)?;
 
     let out_dir = std::env::var("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir).join("config.rs");
     println!("Generating config file: {}", out_path.display());
     std::fs::write(out_path, config_rs)?;
 
-    println!("cargo:rerun-if-changed={}", config_path.display());
+    for layer in &layers {
+        println!("cargo:rerun-if-changed={}", layer.path.display());
+    }
     println!("cargo:rerun-if-env-changed=AX_PLATFORM");
     println!("cargo:rerun-if-env-changed=AX_SMP");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_table(s: &str) -> Table {
+        s.parse::<ImDocument<String>>().unwrap().as_table().clone()
+    }
+
+    fn layer(path: &str, content: &str, origin: &'static str) -> Layer {
+        Layer {
+            path: PathBuf::from(path),
+            table: parse_table(content),
+            content: content.to_string(),
+            origin,
+        }
+    }
+
+    #[test]
+    fn merge_layers_lets_later_layers_override_and_inherit_comments() {
+        let layers = vec![
+            layer(
+                "defconfig.toml",
+                "# the key\nkey = \"base\"\nother = 1\n",
+                "base",
+            ),
+            layer("platform.toml", "key = \"overlay\"\n", "overlay"),
+        ];
+
+        let merged = merge_layers(&layers);
+
+        assert_eq!(merged["key"].as_str(), Some("overlay"));
+        assert_eq!(merged["other"].as_integer(), Some(1));
+        assert_eq!(get_comments(&merged, "key"), Some("# the key"));
+    }
+
+    #[test]
+    fn emit_config_items_infers_types_and_recurses_into_sub_tables() {
+        let table =
+            parse_table("flag = true\noffsets = [-1, 2, 3]\n\n[devices.uart]\nbase = 0x1000\n");
+
+        let mut output = Vec::new();
+        emit_config_items(&mut output, &table, 0, is_num).unwrap();
+        let generated = String::from_utf8(output).unwrap();
+
+        assert!(generated.contains("pub const FLAG: bool = true;"));
+        assert!(generated.contains("pub const OFFSETS: &[isize] = &["));
+        assert!(generated.contains("pub mod devices {"));
+        assert!(generated.contains("pub mod uart {"));
+        assert!(generated.contains("pub const BASE: usize = 0x1000;"));
+    }
+
+    #[test]
+    fn validate_config_accepts_a_schema_conforming_config() {
+        let layers = vec![layer(
+            "defconfig.toml",
+            concat!(
+                "platform = \"dummy\"\n",
+                "arch = \"riscv64\"\n\n",
+                "[schema.arch]\n",
+                "type = \"str\"\n",
+                "allowed = [\"riscv64\", \"aarch64\"]\n",
+            ),
+            "base",
+        )];
+
+        let schema = validate_config(&layers);
+
+        assert!(schema.is_some());
+    }
+}